@@ -0,0 +1,134 @@
+use crossterm::style::{ContentStyle, PrintStyledContent};
+use crossterm::{cursor, queue, terminal};
+use std::io::{self, Write};
+
+/// Minimal set of drawing primitives the board and keyboard renderers
+/// need: move the cursor, print styled text. Implemented once against a
+/// real terminal and once against an in-memory grid, so the rendering
+/// code in `rendering` can run headlessly in tests.
+pub(crate) trait Backend {
+    fn width(&self) -> io::Result<u16>;
+    fn move_to_column(&mut self, col: u16) -> io::Result<()>;
+    fn move_down(&mut self, rows: u16) -> io::Result<()>;
+    fn move_up(&mut self, rows: u16) -> io::Result<()>;
+    fn move_left(&mut self, cols: u16) -> io::Result<()>;
+    fn print(&mut self, text: &str, style: ContentStyle) -> io::Result<()>;
+}
+
+pub(crate) struct CrosstermBackend<'a, T: Write> {
+    stdout: &'a mut T,
+}
+
+impl<'a, T: Write> CrosstermBackend<'a, T> {
+    pub(crate) fn new(stdout: &'a mut T) -> Self {
+        Self { stdout }
+    }
+}
+
+impl<'a, T: Write> Backend for CrosstermBackend<'a, T> {
+    fn width(&self) -> io::Result<u16> {
+        Ok(terminal::size()?.0)
+    }
+
+    fn move_to_column(&mut self, col: u16) -> io::Result<()> {
+        queue!(self.stdout, cursor::MoveToColumn(col))
+    }
+
+    fn move_down(&mut self, rows: u16) -> io::Result<()> {
+        queue!(self.stdout, cursor::MoveDown(rows))
+    }
+
+    fn move_up(&mut self, rows: u16) -> io::Result<()> {
+        queue!(self.stdout, cursor::MoveUp(rows))
+    }
+
+    fn move_left(&mut self, cols: u16) -> io::Result<()> {
+        queue!(self.stdout, cursor::MoveLeft(cols))
+    }
+
+    fn print(&mut self, text: &str, style: ContentStyle) -> io::Result<()> {
+        queue!(self.stdout, PrintStyledContent(style.apply(text.to_string())))
+    }
+}
+
+/// A single cell of an in-memory terminal grid: the character drawn
+/// there and the style it was drawn with.
+#[derive(Clone, Debug)]
+pub(crate) struct Cell {
+    pub(crate) ch: char,
+    pub(crate) style: ContentStyle,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            style: ContentStyle::new(),
+        }
+    }
+}
+
+/// Records everything drawn through it as a grid of styled cells,
+/// without touching a real terminal, so board/keyboard layout can be
+/// asserted on directly in tests.
+pub(crate) struct TestBackend {
+    width: u16,
+    cursor_col: u16,
+    cursor_row: u16,
+    cells: Vec<Vec<Cell>>,
+}
+
+impl TestBackend {
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            cursor_col: 0,
+            cursor_row: 0,
+            cells: vec![vec![Cell::default(); width as usize]; height as usize],
+        }
+    }
+
+    pub(crate) fn cell(&self, col: u16, row: u16) -> &Cell {
+        &self.cells[row as usize][col as usize]
+    }
+}
+
+impl Backend for TestBackend {
+    fn width(&self) -> io::Result<u16> {
+        Ok(self.width)
+    }
+
+    fn move_to_column(&mut self, col: u16) -> io::Result<()> {
+        self.cursor_col = col;
+        Ok(())
+    }
+
+    fn move_down(&mut self, rows: u16) -> io::Result<()> {
+        self.cursor_row += rows;
+        Ok(())
+    }
+
+    fn move_up(&mut self, rows: u16) -> io::Result<()> {
+        self.cursor_row = self.cursor_row.saturating_sub(rows);
+        Ok(())
+    }
+
+    fn move_left(&mut self, cols: u16) -> io::Result<()> {
+        self.cursor_col = self.cursor_col.saturating_sub(cols);
+        Ok(())
+    }
+
+    fn print(&mut self, text: &str, style: ContentStyle) -> io::Result<()> {
+        for ch in text.chars() {
+            if let Some(cell) = self
+                .cells
+                .get_mut(self.cursor_row as usize)
+                .and_then(|row| row.get_mut(self.cursor_col as usize))
+            {
+                *cell = Cell { ch, style };
+            }
+            self.cursor_col += 1;
+        }
+        Ok(())
+    }
+}
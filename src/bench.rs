@@ -0,0 +1,88 @@
+use crate::game::{GameInput, RusdleState, WordSet};
+use crate::solver;
+use rayon::prelude::*;
+use std::io;
+
+/// Outcome of playing the entropy solver against a single answer: the
+/// number of guesses it took to win, or `None` on a loss.
+struct GameResult {
+    guesses: Option<usize>,
+}
+
+/// Plays the entropy solver against every word in `words.wordlist()` and
+/// prints the win rate, average guesses-to-win, and the 1-6 (+ loss)
+/// guess-count histogram. This is the maintainers' regression metric for
+/// solver quality and word-list changes.
+///
+/// Each move scores every word in `words.all_guesses()` (tens of thousands
+/// of words) against the remaining candidates, and this runs for every move
+/// of every answer in the wordlist, so expect this to take several minutes
+/// even with the `rayon` parallelism across answers.
+pub(crate) fn run(words: &WordSet) -> io::Result<()> {
+    let results: Vec<GameResult> = words
+        .wordlist()
+        .par_iter()
+        .map(|answer| play(words.clone(), answer))
+        .collect();
+
+    report(&results);
+    Ok(())
+}
+
+fn play(words: WordSet, answer: &str) -> GameResult {
+    let mut game = RusdleState::new_with_target(words, answer);
+    while !game.is_over() {
+        let guesses_before = game.guesses.len();
+        match solver::suggest(game.words(), &game.guesses) {
+            Some(guess) => {
+                guess
+                    .chars()
+                    .for_each(|c| _ = game.handle_input(GameInput::Input(c)));
+                let _ = game.handle_input(GameInput::Submit);
+            }
+            None => break,
+        }
+        // Guard against a suggested word that `Submit` rejects (e.g. not a
+        // valid 5-letter entry): without this, `guesses` would never grow
+        // and the loop would suggest the same word forever.
+        if game.guesses.len() == guesses_before {
+            break;
+        }
+    }
+
+    GameResult {
+        guesses: game.is_win().then(|| game.guesses.len()),
+    }
+}
+
+fn report(results: &[GameResult]) {
+    let total = results.len();
+    let mut histogram = [0usize; 7]; // [0..6) = guesses 1..=6, [6] = losses
+    let mut wins = 0usize;
+    let mut guess_sum = 0usize;
+
+    for result in results {
+        match result.guesses {
+            Some(n) => {
+                wins += 1;
+                guess_sum += n;
+                histogram[n - 1] += 1;
+            }
+            None => histogram[6] += 1,
+        }
+    }
+
+    println!(
+        "played {} games, {} wins ({:.1}%)",
+        total,
+        wins,
+        100.0 * wins as f64 / total as f64
+    );
+    if wins > 0 {
+        println!("average guesses to win: {:.2}", guess_sum as f64 / wins as f64);
+    }
+    for (i, count) in histogram[..6].iter().enumerate() {
+        println!("  {}: {}", i + 1, count);
+    }
+    println!("  X: {}", histogram[6]);
+}
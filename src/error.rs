@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Failure to apply a `GameInput` to a `RusdleState`. Lets embedders match
+/// on the specific failure instead of parsing a formatted string, so the
+/// core game logic can be used as a library outside the TUI.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GameError {
+    #[error("Word '{0}' is not valid.")]
+    WordNotInWordlist(String),
+    #[error("Guess must be 5 letters, got {got}.")]
+    WrongLength { got: usize },
+    #[error("The game is already over.")]
+    GameAlreadyOver,
+}
@@ -1,4 +1,4 @@
-use crate::MutVecExt;
+use crate::error::GameError;
 use chrono::{DateTime, Local, TimeZone};
 use clap::ArgEnum;
 use rand::seq::SliceRandom;
@@ -68,16 +68,27 @@ impl WordSet {
             .unwrap()
             .to_ascii_uppercase()
     }
+
+    pub(crate) fn wordlist(&self) -> &[String] {
+        &self.wordlist
+    }
+
+    pub(crate) fn all_guesses(&self) -> impl Iterator<Item = &String> {
+        self.wordlist.iter().chain(self.valid_guesses.iter())
+    }
 }
 
 #[derive(Debug)]
 pub(crate) struct RusdleState {
     words: WordSet,
-    target: Vec<char>,
+    target: u64,
     pub(crate) entry: String,
-    pub(crate) last_error: Option<String>,
+    pub(crate) cursor: usize,
+    pub(crate) last_error: Option<GameError>,
     pub(crate) guesses: Vec<(String, [u8; 5])>,
     pub(crate) clues: HashMap<char, u8>,
+    pub(crate) show_hint: bool,
+    pub(crate) show_share: bool,
 }
 
 #[derive(ArgEnum, Clone)]
@@ -88,8 +99,16 @@ pub enum GameMode {
 
 pub enum GameInput {
     Delete,
+    DeleteForward,
     Submit,
     Input(char),
+    MoveLeft,
+    MoveRight,
+    Home,
+    End,
+    Hint,
+    Share,
+    Quit,
 }
 
 impl RusdleState {
@@ -103,34 +122,74 @@ impl RusdleState {
 
     pub fn new_with_target(words: WordSet, target: &str) -> Self {
         assert!(words.is_valid(target));
-        let target = target.chars().collect();
+        let target = pack_word(target);
         Self {
             words,
             target,
             last_error: None,
             entry: String::with_capacity(5),
+            cursor: 0,
             guesses: Vec::with_capacity(6),
             clues: HashMap::with_capacity(26),
+            show_hint: false,
+            show_share: false,
         }
     }
 
-    pub fn handle_input(&mut self, input: GameInput) {
+    pub(crate) fn words(&self) -> &WordSet {
+        &self.words
+    }
+
+    pub fn handle_input(&mut self, input: GameInput) -> Result<(), GameError> {
         match input {
             GameInput::Input(c) => {
                 if self.entry.len() < 5 {
-                    self.entry.push(c.to_ascii_uppercase())
+                    self.entry.insert(self.cursor, c.to_ascii_uppercase());
+                    self.cursor += 1;
                 }
+                Ok(())
             }
             GameInput::Delete => {
-                if self.entry.len() > 0 {
-                    self.entry.pop();
+                if self.cursor > 0 {
+                    self.entry.remove(self.cursor - 1);
+                    self.cursor -= 1;
                 }
+                Ok(())
+            }
+            GameInput::DeleteForward => {
+                if self.cursor < self.entry.len() {
+                    self.entry.remove(self.cursor);
+                }
+                Ok(())
+            }
+            GameInput::MoveLeft => {
+                self.cursor = self.cursor.saturating_sub(1);
+                Ok(())
+            }
+            GameInput::MoveRight => {
+                self.cursor = (self.cursor + 1).min(self.entry.len());
+                Ok(())
+            }
+            GameInput::Home => {
+                self.cursor = 0;
+                Ok(())
+            }
+            GameInput::End => {
+                self.cursor = self.entry.len();
+                Ok(())
             }
-            GameInput::Submit => {
-                if self.entry.len() == 5 {
-                    self.process_guess();
+            GameInput::Submit => self.process_guess(),
+            GameInput::Hint => {
+                self.show_hint = !self.show_hint;
+                Ok(())
+            }
+            GameInput::Share => {
+                if self.is_over() {
+                    self.show_share = !self.show_share;
                 }
+                Ok(())
             }
+            GameInput::Quit => Ok(()),
         }
     }
 
@@ -145,56 +204,127 @@ impl RusdleState {
         }
     }
 
-    fn process_guess(&mut self) {
-        if !(self.words.is_valid(&self.entry)) {
-            self.last_error = Some(format!("Word '{}' is not valid.", self.entry))
-        } else {
-            self.last_error = None;
-            let guess = self.entry.clone();
-            let result = self.compare_guess(&guess);
-
-            guess.chars().zip(result).for_each(|(c, r)| {
-                let clue = self.clues.entry(c).or_insert(r);
-                if r > *clue {
-                    *clue = r
-                }
+    fn process_guess(&mut self) -> Result<(), GameError> {
+        if let Err(err) = self.validate_entry() {
+            self.last_error = Some(err.clone());
+            return Err(err);
+        }
+
+        self.last_error = None;
+        let guess = self.entry.clone();
+        let result = self.compare_guess(&guess);
+
+        guess.chars().zip(result).for_each(|(c, r)| {
+            let clue = self.clues.entry(c).or_insert(r);
+            if r > *clue {
+                *clue = r
+            }
+        });
+
+        self.guesses.push((guess, result));
+        self.entry.clear();
+        self.cursor = 0;
+        Ok(())
+    }
+
+    fn validate_entry(&self) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameAlreadyOver);
+        }
+        if self.entry.len() != 5 {
+            return Err(GameError::WrongLength {
+                got: self.entry.len(),
             });
+        }
+        if !self.words.is_valid(&self.entry) {
+            return Err(GameError::WordNotInWordlist(self.entry.clone()));
+        }
+        Ok(())
+    }
+
+    fn compare_guess(&self, guess: &str) -> [u8; 5] {
+        evaluate(pack_word(guess), self.target)
+    }
+
+    /// The standard Wordle share text for a finished game: a `RUSDLE N/6`
+    /// header followed by the colored-square grid, without the target word.
+    pub fn share_text(&self) -> String {
+        self.to_string().trim_end().to_string()
+    }
+}
+
+impl std::fmt::Display for RusdleState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let score = if self.is_win() {
+            self.guesses.len().to_string()
+        } else {
+            "X".to_string()
+        };
+        writeln!(f, "RUSDLE {}/6", score)?;
 
-            self.guesses.push((guess, result));
-            self.entry.clear()
+        for (_, result) in &self.guesses {
+            for &r in result {
+                f.write_str(match r {
+                    RES_CORRECT => "\u{1F7E9}",
+                    RES_PRESENT => "\u{1F7E8}",
+                    _ => "\u{2B1B}",
+                })?;
+            }
+            writeln!(f)?;
         }
+        Ok(())
     }
+}
 
-    fn compare_guess(&mut self, guess: &str) -> [u8; 5] {
-        let mut unmatched: Vec<char> = self
-            .target
-            .iter()
-            .cloned()
-            .zip(guess.chars())
-            .filter_map(|(actual, guessed)| {
-                if actual != guessed {
-                    Some(actual)
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        guess
-            .char_indices()
-            .map(|(i, c)| {
-                if c == self.target[i] {
-                    RES_CORRECT
-                } else if unmatched.remove_item(c) {
-                    RES_PRESENT
-                } else {
-                    RES_WRONG
-                }
-            })
-            .collect::<Vec<u8>>()
-            .try_into()
-            .unwrap()
+/// Packs a 5-letter word into a `u64` (lowercased, one byte per letter) so
+/// it can be compared with `evaluate` without allocating.
+pub(crate) fn pack_word(word: &str) -> u64 {
+    word.chars()
+        .fold(0u64, |acc, c| (acc << 8) + c.to_ascii_lowercase() as u64)
+}
+
+fn unpack_word(word: u64) -> [u8; 5] {
+    let mut bytes = [0u8; 5];
+    let mut word = word;
+    for byte in bytes.iter_mut().rev() {
+        *byte = (word & 0xff) as u8;
+        word >>= 8;
     }
+    bytes
+}
+
+/// Scores `guess` against `answer` the same way the original per-call
+/// `compare_guess` did, but branch-light and heap-free: a letter-count
+/// array replaces the `Vec<char>` of unmatched letters. First pass marks
+/// `RES_CORRECT` and consumes that letter's count; second pass marks
+/// `RES_PRESENT` while the letter still has count left, else `RES_WRONG`.
+pub(crate) fn evaluate(guess: u64, answer: u64) -> [u8; 5] {
+    let guess = unpack_word(guess);
+    let answer = unpack_word(answer);
+
+    let mut counts = [0u8; 26];
+    for &b in &answer {
+        counts[(b - b'a') as usize] += 1;
+    }
+
+    let mut result = [RES_WRONG; 5];
+    for i in 0..5 {
+        if guess[i] == answer[i] {
+            result[i] = RES_CORRECT;
+            counts[(answer[i] - b'a') as usize] -= 1;
+        }
+    }
+    for i in 0..5 {
+        if result[i] == RES_CORRECT {
+            continue;
+        }
+        let idx = (guess[i] - b'a') as usize;
+        if counts[idx] > 0 {
+            result[i] = RES_PRESENT;
+            counts[idx] -= 1;
+        }
+    }
+    result
 }
 
 pub const RES_DEFAULT: u8 = 0;
@@ -251,4 +381,99 @@ mod tests {
     fn compare_guess_isnt_greedy() {
         assert_eq!(test_game("FRAME").compare_guess("ELIDE"), result("xxxx!"))
     }
+
+    #[test]
+    fn submit_with_wrong_length_returns_typed_error() {
+        let mut game = test_game("MATCH");
+        for c in "MAT".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+
+        assert_eq!(
+            game.handle_input(GameInput::Submit),
+            Err(GameError::WrongLength { got: 3 })
+        );
+    }
+
+    #[test]
+    fn submit_with_unknown_word_returns_typed_error() {
+        let mut game = test_game("MATCH");
+        for c in "ZZZZZ".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+
+        assert_eq!(
+            game.handle_input(GameInput::Submit),
+            Err(GameError::WordNotInWordlist("ZZZZZ".to_string()))
+        );
+    }
+
+    #[test]
+    fn submit_after_game_over_returns_typed_error() {
+        let mut game = test_game("MATCH");
+        game.guesses.push(("MATCH".to_string(), result("!!!!!")));
+        for c in "MATCH".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+
+        assert_eq!(
+            game.handle_input(GameInput::Submit),
+            Err(GameError::GameAlreadyOver)
+        );
+    }
+
+    #[test]
+    fn input_inserts_at_cursor_not_just_append() {
+        let mut game = test_game("MATCH");
+        for c in "MATC".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+        game.handle_input(GameInput::MoveLeft).unwrap();
+        game.handle_input(GameInput::Input('X')).unwrap();
+
+        assert_eq!(game.entry, "MATXC");
+        assert_eq!(game.cursor, 4);
+    }
+
+    #[test]
+    fn delete_forward_at_end_of_entry_is_a_no_op() {
+        let mut game = test_game("MATCH");
+        for c in "MATCH".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+        game.handle_input(GameInput::DeleteForward).unwrap();
+
+        assert_eq!(game.entry, "MATCH");
+        assert_eq!(game.cursor, 5);
+    }
+
+    #[test]
+    fn move_left_clamps_at_start_of_entry() {
+        let mut game = test_game("MATCH");
+        game.handle_input(GameInput::MoveLeft).unwrap();
+        game.handle_input(GameInput::MoveLeft).unwrap();
+
+        assert_eq!(game.cursor, 0);
+    }
+
+    #[test]
+    fn cursor_resets_to_zero_after_submit() {
+        let mut game = test_game("MATCH");
+        for c in "MATCH".chars() {
+            game.handle_input(GameInput::Input(c)).unwrap();
+        }
+        game.handle_input(GameInput::Submit).unwrap();
+
+        assert_eq!(game.cursor, 0);
+    }
+
+    #[test]
+    fn share_text_renders_emoji_grid_without_target() {
+        let mut game = test_game("MATCH");
+        game.guesses.push(("MATCH".to_string(), result("!!!!!")));
+        assert_eq!(
+            game.share_text(),
+            "RUSDLE 1/6\n\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}"
+        );
+    }
 }
@@ -1,15 +1,21 @@
-use clap::Parser;
-use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+use clap::{Parser, Subcommand};
 use game::{GameInput, GameMode, RusdleState, WordSet};
+use renderer::Renderer;
 use std::io;
 use std::path::PathBuf;
 
+mod backend;
+mod bench;
+mod error;
 mod game;
 mod renderer;
 mod rendering;
+mod solver;
 
 #[derive(Parser)]
 struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
     #[clap(arg_enum, default_value_t = game::GameMode::Wordle)]
     mode: GameMode,
     #[clap(short, long, parse(from_os_str), value_name = "FILE")]
@@ -18,57 +24,36 @@ struct Cli {
     dictionary: Option<PathBuf>,
 }
 
+#[derive(Subcommand)]
+enum Command {
+    /// Play the entropy solver against every word in the wordlist and report aggregate stats.
+    Bench,
+}
+
 fn main() -> Result<(), io::Error> {
     let cli = Cli::parse();
-    let mut game = RusdleState::new(WordSet::load(cli.word_list, cli.dictionary)?, cli.mode);
+    let words = WordSet::load(cli.word_list, cli.dictionary)?;
+
+    if let Some(Command::Bench) = cli.command {
+        return bench::run(&words);
+    }
+
+    let mut game = RusdleState::new(words, cli.mode);
 
-    renderer::Renderer::with(|r| {
+    renderer::with_terminal(|r| {
         loop {
             r.render(&game)?;
             if game.is_over() {
                 break;
             }
-            match read()? {
-                Event::Key(event) => match event {
-                    KeyEvent {
-                        modifiers: KeyModifiers::CONTROL,
-                        code: KeyCode::Char('c'),
-                    } => break,
-                    KeyEvent {
-                        modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
-                        code,
-                    } => {
-                        if let Some(input) = match code {
-                            KeyCode::Char(c) if c.is_ascii_alphabetic() => {
-                                Some(GameInput::Input(c.to_ascii_uppercase()))
-                            }
-                            KeyCode::Backspace => Some(GameInput::Delete),
-                            KeyCode::Enter => Some(GameInput::Submit),
-                            _ => None,
-                        } {
-                            game.handle_input(input)
-                        }
-                    }
-                    _ => {}
-                },
-                _ => {}
+            match r.next_input()? {
+                Some(GameInput::Quit) => break,
+                Some(input) => {
+                    let _ = game.handle_input(input);
+                }
+                None => {}
             }
         }
         Ok(())
     })
 }
-
-trait MutVecExt<T> {
-    fn remove_item(&mut self, val: T) -> bool;
-}
-
-impl<T: PartialEq> MutVecExt<T> for Vec<T> {
-    fn remove_item(&mut self, val: T) -> bool {
-        if let Some(idx) = self.iter().position(|x| *x == val) {
-            self.swap_remove(idx);
-            true
-        } else {
-            false
-        }
-    }
-}
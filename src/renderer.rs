@@ -1,13 +1,15 @@
 use crate::{
-    game::{GameInput, RusdleState, RES_CORRECT, RES_DEFAULT, RES_PRESENT, RES_WRONG},
-    rendering::{render_boxed_word, render_message_centered},
+    backend::{Backend, CrosstermBackend},
+    game::{GameInput, RusdleState, RES_DEFAULT},
+    rendering::{render_boxed_word, render_keyboard, render_message_centered, result_colours},
+    solver,
 };
 use crossterm::{
-    cursor::{self, MoveDown, MoveLeft, MoveTo, MoveToColumn},
+    cursor::{self, MoveDown, MoveToColumn},
     event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
     execute, queue,
-    style::{Color, ContentStyle, PrintStyledContent, ResetColor, Stylize},
-    terminal::{self, disable_raw_mode, enable_raw_mode, Clear, ClearType},
+    style::{ContentStyle, ResetColor, Stylize},
+    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
 };
 use std::{io, iter::repeat};
 
@@ -22,6 +24,7 @@ pub(crate) fn with_terminal<F: FnOnce(&mut dyn Renderer) -> io::Result<()>>(
     let stdout = Box::leak(Box::new(io::stdout()));
     let mut r = TerminalRenderer {
         stdout: stdout.lock(),
+        hint_cache: None,
     };
     r.init()?;
     func(&mut r)?;
@@ -30,6 +33,10 @@ pub(crate) fn with_terminal<F: FnOnce(&mut dyn Renderer) -> io::Result<()>>(
 
 struct TerminalRenderer {
     stdout: std::io::StdoutLock<'static>,
+    /// The last computed hint, keyed by the guess count it was computed
+    /// for, so `solver::suggest`'s scan over every valid guess only reruns when
+    /// a new guess is submitted instead of on every render.
+    hint_cache: Option<(usize, String)>,
 }
 
 impl TerminalRenderer {
@@ -51,84 +58,68 @@ impl TerminalRenderer {
 
 impl TerminalRenderer {
     fn render_header(&mut self) -> io::Result<()> {
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
         render_boxed_word(
-            &mut self.stdout,
+            &mut backend,
             "RUSDLE",
             repeat(ContentStyle::new().blue().bold().italic()),
         )?;
-        render_message_centered(&mut self.stdout, "Wordle in Rust".bold())?;
+        render_message_centered(&mut backend, "Wordle in Rust".bold())?;
         queue!(self.stdout, MoveDown(1))
     }
 
     fn render_guesses(&mut self, state: &RusdleState) -> io::Result<()> {
-        let mut render_guess = |guess: &str, result: &[u8; 5]| {
-            render_boxed_word(
-                &mut self.stdout,
-                &guess,
-                result.iter().map(|r| result_colours(*r)),
-            )
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
+        let mut render_guess = |guess: &str, styles: Vec<ContentStyle>| {
+            render_boxed_word(&mut backend, guess, styles.into_iter())
         };
         for (guess, result) in state.guesses.iter() {
-            render_guess(guess, result)?;
+            render_guess(guess, result.iter().map(|r| result_colours(*r)).collect())?;
         }
         if !state.is_over() {
-            render_guess(&format!("{}_    ", state.entry)[..5], &[RES_DEFAULT; 5])?;
+            let entry = format!("{:<5}", state.entry);
+            let mut styles = vec![result_colours(RES_DEFAULT); 5];
+            if let Some(cursor_style) = styles.get_mut(state.cursor) {
+                *cursor_style = cursor_style.underlined();
+            }
+            render_guess(&entry, styles)?;
             for _ in state.guesses.len()..5 {
-                render_guess("     ", &[RES_DEFAULT; 5])?;
+                render_guess("     ", vec![result_colours(RES_DEFAULT); 5])?;
             }
         } else {
             for _ in state.guesses.len()..6 {
-                render_guess("     ", &[RES_DEFAULT; 5])?;
+                render_guess("     ", vec![result_colours(RES_DEFAULT); 5])?;
             }
         }
         Ok(())
     }
 
     fn render_keyboard(&mut self, state: &RusdleState) -> io::Result<()> {
-        let (cols, _) = terminal::size()?;
-        let mut render_keyrow = |row: &str| -> io::Result<()> {
-            queue!(
-                &mut self.stdout,
-                MoveToColumn(cols / 2 - row.len() as u16),
-                MoveDown(1)
-            )?;
-            let mut prev_style = ContentStyle::new();
-            for c in row.chars() {
-                if c == ' ' {
-                    continue;
-                }
-                let style = match state.clues.get(&c).map(|r| *r) {
-                    Some(RES_WRONG) => ContentStyle::new()
-                        .dark_grey()
-                        .on(Color::from((32, 32, 32))),
-                    Some(r) => result_colours(r),
-                    None => ContentStyle::new().black().on_dark_grey(),
-                };
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
+        render_keyboard(&mut backend, &state.clues)
+    }
 
-                queue!(
-                    self.stdout,
-                    PrintStyledContent(
-                        prev_style
-                            .clone()
-                            .with(style.background_color.unwrap_or(Color::White))
-                            .apply('▐')
-                    ),
-                    PrintStyledContent(style.apply(c)),
-                    PrintStyledContent(style.clone().black().apply('▐')),
-                    MoveLeft(1),
-                )?;
-                prev_style = style;
-            }
-            Ok(())
-        };
+    fn render_hint(&mut self, state: &RusdleState) -> io::Result<()> {
+        if !state.show_hint || state.is_over() {
+            return Ok(());
+        }
 
-        render_keyrow("QWERTYUIOP")?;
-        render_keyrow("ASDFGHJKL")?;
-        render_keyrow("ZXCVBNM ")?;
-        queue!(self.stdout, MoveDown(2))
+        let guess_count = state.guesses.len();
+        if self.hint_cache.as_ref().map(|(n, _)| *n) != Some(guess_count) {
+            let hint = match solver::suggest(state.words(), &state.guesses) {
+                Some(word) => format!("Hint: {}", word),
+                None => "Hint: no candidates left".to_string(),
+            };
+            self.hint_cache = Some((guess_count, hint));
+        }
+
+        let hint = &self.hint_cache.as_ref().unwrap().1;
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
+        render_message_centered(&mut backend, hint.as_str().dim())
     }
 
     fn render_message(&mut self, state: &RusdleState) -> Result<(), io::Error> {
+        let error_text = state.last_error.as_ref().map(|err| err.to_string());
         let message = if state.is_over() {
             if state.is_win() {
                 "Winner!".green()
@@ -136,13 +127,26 @@ impl TerminalRenderer {
                 "Loser!".red()
             }
         } else {
-            match &state.last_error {
+            match &error_text {
                 Some(msg) => msg.as_str().dark_yellow(),
-                _ => "".stylize(),
+                None => "".stylize(),
             }
         };
 
-        render_message_centered(&mut self.stdout, message.slow_blink())
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
+        render_message_centered(&mut backend, message.slow_blink())
+    }
+
+    fn render_share(&mut self, state: &RusdleState) -> io::Result<()> {
+        if !state.show_share || !state.is_over() {
+            return Ok(());
+        }
+        let mut backend = CrosstermBackend::new(&mut self.stdout);
+        for line in state.share_text().lines() {
+            render_message_centered(&mut backend, line.stylize())?;
+            backend.move_down(1)?;
+        }
+        Ok(())
     }
 }
 
@@ -162,7 +166,14 @@ impl Renderer for TerminalRenderer {
                         Some(GameInput::Input(c.to_ascii_uppercase()))
                     }
                     KeyCode::Backspace => Some(GameInput::Delete),
+                    KeyCode::Delete => Some(GameInput::DeleteForward),
+                    KeyCode::Left => Some(GameInput::MoveLeft),
+                    KeyCode::Right => Some(GameInput::MoveRight),
+                    KeyCode::Home => Some(GameInput::Home),
+                    KeyCode::End => Some(GameInput::End),
                     KeyCode::Enter => Some(GameInput::Submit),
+                    KeyCode::Tab => Some(GameInput::Hint),
+                    KeyCode::Char(' ') => Some(GameInput::Share),
                     _ => None,
                 },
                 _ => None,
@@ -172,24 +183,18 @@ impl Renderer for TerminalRenderer {
     }
 
     fn render(&mut self, state: &RusdleState) -> io::Result<()> {
-        queue!(self.stdout, Clear(ClearType::All), ResetColor, MoveTo(0, 0))?;
+        queue!(
+            self.stdout,
+            Clear(ClearType::All),
+            ResetColor,
+            cursor::MoveTo(0, 0)
+        )?;
         self.render_header()?;
         self.render_guesses(&state)?;
         self.render_keyboard(&state)?;
+        self.render_hint(&state)?;
         self.render_message(&state)?;
+        self.render_share(&state)?;
         execute!(self.stdout, MoveDown(1), MoveToColumn(0))
     }
 }
-
-fn result_colours(r: u8) -> ContentStyle {
-    match r {
-        RES_DEFAULT => ContentStyle::new()
-            .white()
-            .on(Color::from((32, 32, 32)))
-            .bold(),
-        RES_WRONG => ContentStyle::new().black().on_dark_grey(),
-        RES_PRESENT => ContentStyle::new().black().on_dark_yellow().bold(),
-        RES_CORRECT => ContentStyle::new().black().on_dark_green().bold(),
-        _ => panic!("unknown char result {}", r),
-    }
-}
@@ -1,67 +1,156 @@
-use crossterm::{
-    cursor::{MoveDown, MoveLeft, MoveToColumn, MoveUp},
-    queue,
-    style::{ContentStyle, PrintStyledContent, StyledContent, Stylize},
-    terminal,
-};
-use std::io::{self, Write};
+use crate::backend::Backend;
+use crate::game::{RES_CORRECT, RES_DEFAULT, RES_PRESENT, RES_WRONG};
+use crossterm::style::{Color, ContentStyle, StyledContent, Stylize};
+use std::collections::HashMap;
+use std::io;
 
-pub(crate) fn render_boxed_word<T: Write, I>(mut stdout: T, word: &str, styles: I) -> io::Result<()>
+pub(crate) fn render_boxed_word<B: Backend + ?Sized, I>(
+    backend: &mut B,
+    word: &str,
+    styles: I,
+) -> io::Result<()>
 where
     I: Iterator<Item = ContentStyle>,
 {
-    let x = (terminal::size()?.0 / 2) - (4 * word.len() as u16 - 1) / 2;
+    let x = (backend.width()? / 2) - (4 * word.len() as u16 - 1) / 2;
     for ((ci, c), style) in word.char_indices().zip(styles) {
-        let s = &mut stdout;
         let cx = x + (ci as u16 * 4);
-        queue!(s, MoveToColumn(cx))?;
-        draw_charbox(s, c, style)?;
+        backend.move_to_column(cx)?;
+        draw_charbox(backend, c, style)?;
     }
-    queue!(stdout, MoveDown(3))
+    backend.move_down(3)
 }
 
-pub(crate) fn render_message_centered<T: Write>(
-    mut stdout: T,
+pub(crate) fn render_message_centered<B: Backend + ?Sized>(
+    backend: &mut B,
     message: StyledContent<&str>,
 ) -> io::Result<()> {
-    queue!(
-        stdout,
-        MoveToColumn(terminal::size()?.0 / 2 - message.content().len() as u16 / 2),
-        PrintStyledContent(message)
-    )
+    let col = backend.width()? / 2 - message.content().len() as u16 / 2;
+    backend.move_to_column(col)?;
+    backend.print(message.content(), *message.style())
 }
 
-fn draw_charbox<T: Write>(mut stdout: T, c: char, style: ContentStyle) -> io::Result<()> {
+pub(crate) fn render_keyboard<B: Backend + ?Sized>(
+    backend: &mut B,
+    clues: &HashMap<char, u8>,
+) -> io::Result<()> {
+    let cols = backend.width()?;
+    render_keyrow(backend, cols, "QWERTYUIOP", clues)?;
+    render_keyrow(backend, cols, "ASDFGHJKL", clues)?;
+    render_keyrow(backend, cols, "ZXCVBNM ", clues)?;
+    backend.move_down(2)
+}
+
+fn render_keyrow<B: Backend + ?Sized>(
+    backend: &mut B,
+    cols: u16,
+    row: &str,
+    clues: &HashMap<char, u8>,
+) -> io::Result<()> {
+    backend.move_to_column(cols / 2 - row.len() as u16)?;
+    backend.move_down(1)?;
+
+    let mut prev_style = ContentStyle::new();
+    for c in row.chars() {
+        if c == ' ' {
+            continue;
+        }
+        let style = match clues.get(&c).copied() {
+            Some(RES_WRONG) => ContentStyle::new()
+                .dark_grey()
+                .on(Color::from((32, 32, 32))),
+            Some(r) => result_colours(r),
+            None => ContentStyle::new().black().on_dark_grey(),
+        };
+
+        backend.print(
+            "▐",
+            prev_style
+                .clone()
+                .with(style.background_color.unwrap_or(Color::White)),
+        )?;
+        backend.print(&c.to_string(), style)?;
+        backend.print("▐", style.clone().black())?;
+        backend.move_left(1)?;
+        prev_style = style;
+    }
+    Ok(())
+}
+
+fn draw_charbox<B: Backend + ?Sized>(backend: &mut B, c: char, style: ContentStyle) -> io::Result<()> {
     match style {
         ContentStyle {
             background_color: None,
             ..
-        } => queue!(
-            stdout,
-            PrintStyledContent(style.apply("\u{250c}\u{2500}\u{2510}")),
-            MoveDown(1),
-            MoveLeft(3),
-            PrintStyledContent(style.apply("\u{2502} \u{2502}")),
-            MoveDown(1),
-            MoveLeft(3),
-            PrintStyledContent(style.apply("\u{2514}\u{2500}\u{2518}")),
-            MoveUp(1),
-            MoveLeft(2),
-            PrintStyledContent(style.apply(c)),
-            MoveUp(1),
-            MoveLeft(1),
-        ),
-        _ => queue!(
-            stdout,
-            PrintStyledContent(style.black().negative().apply("\u{2584}\u{2584}\u{2584}")),
-            MoveDown(1),
-            MoveLeft(3),
-            PrintStyledContent(style.apply(format!(" {} ", c))),
-            MoveDown(1),
-            MoveLeft(3),
-            PrintStyledContent(style.black().negative().apply("\u{2580}\u{2580}\u{2580}")),
-            MoveUp(2),
-            MoveLeft(2),
-        ),
+        } => {
+            backend.print("\u{250c}\u{2500}\u{2510}", style)?;
+            backend.move_down(1)?;
+            backend.move_left(3)?;
+            backend.print("\u{2502} \u{2502}", style)?;
+            backend.move_down(1)?;
+            backend.move_left(3)?;
+            backend.print("\u{2514}\u{2500}\u{2518}", style)?;
+            backend.move_up(1)?;
+            backend.move_left(2)?;
+            backend.print(&c.to_string(), style)?;
+            backend.move_up(1)?;
+            backend.move_left(1)
+        }
+        _ => {
+            backend.print("\u{2584}\u{2584}\u{2584}", style.black().negative())?;
+            backend.move_down(1)?;
+            backend.move_left(3)?;
+            backend.print(&format!(" {} ", c), style)?;
+            backend.move_down(1)?;
+            backend.move_left(3)?;
+            backend.print("\u{2580}\u{2580}\u{2580}", style.black().negative())?;
+            backend.move_up(2)?;
+            backend.move_left(2)
+        }
+    }
+}
+
+pub(crate) fn result_colours(r: u8) -> ContentStyle {
+    match r {
+        RES_DEFAULT => ContentStyle::new()
+            .white()
+            .on(Color::from((32, 32, 32)))
+            .bold(),
+        RES_WRONG => ContentStyle::new().black().on_dark_grey(),
+        RES_PRESENT => ContentStyle::new().black().on_dark_yellow().bold(),
+        RES_CORRECT => ContentStyle::new().black().on_dark_green().bold(),
+        _ => panic!("unknown char result {}", r),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn render_boxed_word_draws_a_box_per_letter() {
+        let mut backend = TestBackend::new(20, 4);
+        render_boxed_word(&mut backend, "AB", std::iter::repeat(ContentStyle::new())).unwrap();
+
+        assert_eq!(backend.cell(7, 0).ch, '\u{250c}');
+        assert_eq!(backend.cell(8, 1).ch, 'A');
+        assert_eq!(backend.cell(12, 1).ch, 'B');
+    }
+
+    #[test]
+    fn render_keyboard_colours_clued_letters() {
+        let mut backend = TestBackend::new(40, 4);
+        let mut clues = HashMap::new();
+        clues.insert('Q', RES_CORRECT);
+
+        render_keyboard(&mut backend, &clues).unwrap();
+
+        let q_cell = backend.cell(11, 1);
+        assert_eq!(q_cell.ch, 'Q');
+        assert_eq!(
+            q_cell.style.background_color,
+            result_colours(RES_CORRECT).background_color
+        );
     }
 }
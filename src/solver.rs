@@ -0,0 +1,113 @@
+use crate::game::{evaluate, pack_word, WordSet};
+
+/// Number of distinct feedback patterns for a 5-letter guess (3^5), each
+/// encoded as a base-3 integer over the `RES_WRONG`/`RES_PRESENT`/`RES_CORRECT`
+/// digits.
+const PATTERN_COUNT: usize = 243;
+
+/// Suggests the next guess with the highest expected information gain
+/// against the answers still consistent with the guesses made so far.
+///
+/// Candidates are drawn from `words.wordlist()` (the possible answers),
+/// while guesses are scored from `words.all_guesses()` (every word the
+/// player could legally submit), so the solver may suggest a probe word
+/// that can no longer be the answer itself.
+pub(crate) fn suggest(words: &WordSet, guesses: &[(String, [u8; 5])]) -> Option<String> {
+    let candidates = remaining_candidates(words, guesses);
+    if candidates.is_empty() {
+        return None;
+    }
+
+    words
+        .all_guesses()
+        .map(|word| {
+            let packed = pack_word(word);
+            (word, entropy(packed, &candidates), candidates.contains(&packed))
+        })
+        .max_by(|(_, a_entropy, a_in_candidates), (_, b_entropy, b_in_candidates)| {
+            a_entropy
+                .partial_cmp(b_entropy)
+                .unwrap()
+                .then_with(|| a_in_candidates.cmp(b_in_candidates))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+fn remaining_candidates(words: &WordSet, guesses: &[(String, [u8; 5])]) -> Vec<u64> {
+    words
+        .wordlist()
+        .iter()
+        .map(|candidate| pack_word(candidate))
+        .filter(|&target| {
+            guesses
+                .iter()
+                .all(|(guess, result)| evaluate(pack_word(guess), target) == *result)
+        })
+        .collect()
+}
+
+/// Shannon entropy of the feedback-pattern distribution `guess` would
+/// induce over `candidates`: bucket every candidate by the pattern it
+/// yields, turn counts into probabilities, then `H = -Σ p·log2(p)`.
+fn entropy(guess: u64, candidates: &[u64]) -> f64 {
+    let mut buckets = [0u32; PATTERN_COUNT];
+    for &candidate in candidates {
+        buckets[pattern_index(&evaluate(guess, candidate))] += 1;
+    }
+
+    let total = candidates.len() as f64;
+    buckets
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn pattern_index(pattern: &[u8; 5]) -> usize {
+    pattern
+        .iter()
+        .fold(0usize, |acc, &r| acc * 3 + (r - 1) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{evaluate, RES_CORRECT, RES_WRONG};
+
+    fn default_words() -> WordSet {
+        WordSet::load(None::<&str>, None::<&str>).unwrap()
+    }
+
+    #[test]
+    fn pattern_index_encodes_base_3() {
+        assert_eq!(pattern_index(&[RES_WRONG; 5]), 0);
+        assert_eq!(pattern_index(&[RES_CORRECT; 5]), 242);
+    }
+
+    #[test]
+    fn entropy_is_zero_for_a_single_bucket() {
+        let candidates = vec![pack_word("abcde"), pack_word("abcde")];
+        assert_eq!(entropy(pack_word("abcde"), &candidates), 0.0);
+    }
+
+    #[test]
+    fn entropy_of_two_distinct_patterns_is_one_bit() {
+        let candidates = vec![pack_word("aaaaa"), pack_word("bbbbb")];
+        assert_eq!(entropy(pack_word("aaaaa"), &candidates), 1.0);
+    }
+
+    #[test]
+    fn remaining_candidates_filters_by_past_guess_feedback() {
+        let words = default_words();
+        let guesses = vec![("AAAAA".to_string(), [RES_WRONG; 5])];
+
+        let candidates = remaining_candidates(&words, &guesses);
+
+        assert!(candidates
+            .iter()
+            .all(|&target| evaluate(pack_word("AAAAA"), target) == [RES_WRONG; 5]));
+    }
+}